@@ -8,6 +8,17 @@ use num_complex::Complex;
 use num_traits::One;
 use std::cell::Cell;
 
+/// The smallest size `>= min` that `Stages::new` can factor, found by linear search. Used by
+/// Bluestein's algorithm and the convolution helpers to pick a transform length the mixed-radix
+/// engine can actually run.
+pub(crate) fn smallest_supported_size<T: FftFloat>(min: usize) -> usize {
+    let mut size = min;
+    while Stages::<T>::new(size).is_none() {
+        size += 1;
+    }
+    size
+}
+
 fn num_factors(factor: usize, mut value: usize) -> (usize, usize) {
     let mut count = 0;
     while value % factor == 0 {
@@ -39,7 +50,7 @@ fn extend_twiddles<T: FftFloat>(
     }
 }
 
-struct Stages<T> {
+pub(crate) struct Stages<T> {
     size: usize,
     stages: Vec<(usize, usize)>,
     forward_twiddles: Vec<Complex<T>>,
@@ -47,12 +58,40 @@ struct Stages<T> {
 }
 
 impl<T: FftFloat> Stages<T> {
-    fn new(size: usize) -> Option<Self> {
+    pub(crate) fn new(size: usize) -> Option<Self> {
         let mut current_size = size;
         let mut stages = Vec::new();
         let mut forward_twiddles = Vec::new();
         let mut reverse_twiddles = Vec::new();
 
+        {
+            let (count, new_size) = num_factors(7, current_size);
+            if count > 0 {
+                stages.push((7, count));
+                extend_twiddles(
+                    &mut forward_twiddles,
+                    &mut reverse_twiddles,
+                    current_size,
+                    7,
+                    count,
+                );
+            }
+            current_size = new_size;
+        }
+        {
+            let (count, new_size) = num_factors(5, current_size);
+            if count > 0 {
+                stages.push((5, count));
+                extend_twiddles(
+                    &mut forward_twiddles,
+                    &mut reverse_twiddles,
+                    current_size,
+                    5,
+                    count,
+                );
+            }
+            current_size = new_size;
+        }
         {
             let (count, new_size) = num_factors(4, current_size);
             if count > 0 {
@@ -115,7 +154,7 @@ macro_rules! make_radix_fns {
         @impl $type:ty, $width:ident, $radix:literal, $name:ident, $butterfly:ident
     } => {
 
-        #[multiversion::target_clones("[x86|x86_64]+avx")]
+        #[multiversion::target_clones("[x86|x86_64]+avx", "aarch64+neon")]
         #[inline]
         pub(super) fn $name(
             input: &[num_complex::Complex<$type>],
@@ -128,7 +167,10 @@ macro_rules! make_radix_fns {
             #[target_cfg(target = "[x86|x86_64]+avx")]
             crate::avx_vector! { $type };
 
-            #[target_cfg(not(target = "[x86|x86_64]+avx"))]
+            #[target_cfg(target = "aarch64+neon")]
+            crate::neon_vector! { $type };
+
+            #[target_cfg(not(any(target = "[x86|x86_64]+avx", target = "aarch64+neon")))]
             crate::generic_vector! { $type };
 
             #[target_cfg(target = "[x86|x86_64]+avx")]
@@ -138,6 +180,13 @@ macro_rules! make_radix_fns {
                 }
             }
 
+            #[target_cfg(target = "aarch64+neon")]
+            {
+                if crate::neon_optimization!($type, $width, $radix, input, output, _forward, size, stride, twiddles) {
+                    return
+                }
+            }
+
             let get_twiddle = |i, j| unsafe { *twiddles.get_unchecked(j * $radix + i) };
             crate::stage!(
                 $width,
@@ -156,12 +205,16 @@ macro_rules! make_radix_fns {
         $([$radix:literal, $wide_name:ident, $narrow_name:ident, $butterfly:ident]),*
     } => {
         mod radix_f32 {
+        #[allow(unused_imports)]
+        use crate::butterfly::{butterfly5, butterfly7};
         $(
             make_radix_fns! { @impl f32, wide, $radix, $wide_name, $butterfly }
             make_radix_fns! { @impl f32, narrow, $radix, $narrow_name, $butterfly }
         )*
         }
         mod radix_f64 {
+        #[allow(unused_imports)]
+        use crate::butterfly::{butterfly5, butterfly7};
         $(
             make_radix_fns! { @impl f64, wide, $radix, $wide_name, $butterfly }
             make_radix_fns! { @impl f64, narrow, $radix, $narrow_name, $butterfly }
@@ -173,15 +226,17 @@ macro_rules! make_radix_fns {
 make_radix_fns! {
     [2, radix_2_wide, radix_2_narrow, butterfly2],
     [3, radix_3_wide, radix_3_narrow, butterfly3],
-    [4, radix_4_wide, radix_4_narrow, butterfly4]
+    [4, radix_4_wide, radix_4_narrow, butterfly4],
+    [5, radix_5_wide, radix_5_narrow, butterfly5],
+    [7, radix_7_wide, radix_7_narrow, butterfly7]
 }
 
 /// This macro creates the stage application function.
 macro_rules! make_stage_fns {
     { $type:ty, $name:ident, $radix_mod:ident } => {
-        #[multiversion::target_clones("[x86|x86_64]+avx")]
+        #[multiversion::target_clones("[x86|x86_64]+avx", "aarch64+neon")]
         #[inline]
-        fn $name(
+        pub(crate) fn $name(
             input: &mut [Complex<$type>],
             output: &mut [Complex<$type>],
             stages: &Stages<$type>,
@@ -199,11 +254,22 @@ macro_rules! make_stage_fns {
             use $radix_mod::radix_4_narrow;
             #[static_dispatch]
             use $radix_mod::radix_4_wide;
+            #[static_dispatch]
+            use $radix_mod::radix_5_narrow;
+            #[static_dispatch]
+            use $radix_mod::radix_5_wide;
+            #[static_dispatch]
+            use $radix_mod::radix_7_narrow;
+            #[static_dispatch]
+            use $radix_mod::radix_7_wide;
 
             #[target_cfg(target = "[x86|x86_64]+avx")]
             crate::avx_vector! { $type };
 
-            #[target_cfg(not(target = "[x86|x86_64]+avx"))]
+            #[target_cfg(target = "aarch64+neon")]
+            crate::neon_vector! { $type };
+
+            #[target_cfg(not(any(target = "[x86|x86_64]+avx", target = "aarch64+neon")))]
             crate::generic_vector! { $type };
 
             assert_eq!(input.len(), output.len());
@@ -229,6 +295,8 @@ macro_rules! make_stage_fns {
                         (input, output)
                     };
                     match radix {
+                        7 => radix_7_narrow(from, to, forward, size, stride, twiddles),
+                        5 => radix_5_narrow(from, to, forward, size, stride, twiddles),
                         4 => radix_4_narrow(from, to, forward, size, stride, twiddles),
                         3 => radix_3_narrow(from, to, forward, size, stride, twiddles),
                         2 => radix_2_narrow(from, to, forward, size, stride, twiddles),
@@ -248,6 +316,8 @@ macro_rules! make_stage_fns {
                         (input, output)
                     };
                     match radix {
+                        7 => radix_7_wide(from, to, forward, size, stride, twiddles),
+                        5 => radix_5_wide(from, to, forward, size, stride, twiddles),
                         4 => radix_4_wide(from, to, forward, size, stride, twiddles),
                         3 => radix_3_wide(from, to, forward, size, stride, twiddles),
                         2 => radix_2_wide(from, to, forward, size, stride, twiddles),
@@ -315,11 +385,17 @@ impl Fft for PrimeFactor32 {
     }
 }
 
-pub fn create_f32(size: usize) -> Option<Box<dyn Fft<Real = f32> + Send>> {
+/// Creates an FFT plan for the given size. Sizes that the mixed-radix engine can factor
+/// (products of 2, 3, 4, 5 and 7) use that engine directly. Prime sizes whose predecessor
+/// factors cleanly use Rader's algorithm, which avoids Bluestein's zero-padding overhead.
+/// Everything else falls back to Bluestein's algorithm, so this never fails.
+pub fn create_f32(size: usize) -> Box<dyn Fft<Real = f32> + Send> {
     if let Some(fft) = PrimeFactor32::new(size) {
-        Some(Box::new(fft))
+        Box::new(fft)
+    } else if let Some(fft) = crate::raders::Rader32::new(size) {
+        Box::new(fft)
     } else {
-        None
+        Box::new(crate::bluesteins::Bluestein32::new(size).expect("Bluestein's algorithm supports any size"))
     }
 }
 
@@ -357,10 +433,16 @@ impl Fft for PrimeFactor64 {
     }
 }
 
-pub fn create_f64(size: usize) -> Option<Box<dyn Fft<Real = f64> + Send>> {
+/// Creates an FFT plan for the given size. Sizes that the mixed-radix engine can factor
+/// (products of 2, 3, 4, 5 and 7) use that engine directly. Prime sizes whose predecessor
+/// factors cleanly use Rader's algorithm, which avoids Bluestein's zero-padding overhead.
+/// Everything else falls back to Bluestein's algorithm, so this never fails.
+pub fn create_f64(size: usize) -> Box<dyn Fft<Real = f64> + Send> {
     if let Some(fft) = PrimeFactor64::new(size) {
-        Some(Box::new(fft))
+        Box::new(fft)
+    } else if let Some(fft) = crate::raders::Rader64::new(size) {
+        Box::new(fft)
     } else {
-        None
+        Box::new(crate::bluesteins::Bluestein64::new(size).expect("Bluestein's algorithm supports any size"))
     }
 }
@@ -0,0 +1,122 @@
+//! Bluestein's chirp-z transform, used as the final fallback for sizes that neither the
+//! mixed-radix engine nor Rader's algorithm can handle.
+use crate::autosort::prime_factor::{apply_stages_f32, apply_stages_f64, smallest_supported_size, Stages};
+use crate::fft::Fft;
+use crate::float::FftFloat;
+use num_complex::Complex;
+use num_traits::NumCast;
+use std::cell::Cell;
+
+fn cast<T: FftFloat>(x: f64) -> T {
+    NumCast::from(x).unwrap()
+}
+
+/// `exp(sign * i * pi * n^2 / size)`, with `n^2` reduced modulo `2 * size` up front to keep the
+/// angle (and therefore the trig evaluation) well-conditioned for large `n`.
+fn chirp<T: FftFloat>(n: usize, size: usize, sign: f64) -> Complex<T> {
+    let reduced = (n as u128 * n as u128) % (2 * size as u128);
+    let angle = sign * std::f64::consts::PI * reduced as f64 / size as f64;
+    Complex::new(cast(angle.cos()), cast(angle.sin()))
+}
+
+macro_rules! make_bluestein {
+    { $type:ty, $name:ident, $apply_stages:ident } => {
+        pub(crate) struct $name {
+            size: usize,
+            conv_size: usize,
+            forward_chirp: Vec<Complex<$type>>,
+            reverse_chirp: Vec<Complex<$type>>,
+            forward_kernel: Vec<Complex<$type>>,
+            reverse_kernel: Vec<Complex<$type>>,
+            stages: Stages<$type>,
+            work: Cell<Box<[Complex<$type>]>>,
+            buffer: Cell<Box<[Complex<$type>]>>,
+        }
+
+        impl $name {
+            pub(crate) fn new(size: usize) -> Option<Self> {
+                let conv_size = smallest_supported_size::<$type>(2 * size - 1);
+                let stages = Stages::new(conv_size)?;
+
+                let forward_chirp: Vec<_> = (0..size).map(|n| chirp(n, size, -1.0)).collect();
+                let reverse_chirp: Vec<_> = (0..size).map(|n| chirp(n, size, 1.0)).collect();
+
+                let build_kernel = |chirp: &[Complex<$type>]| {
+                    let mut kernel = vec![Complex::default(); conv_size];
+                    kernel[0] = chirp[0].conj();
+                    for n in 1..size {
+                        kernel[n] = chirp[n].conj();
+                        kernel[conv_size - n] = chirp[n].conj();
+                    }
+                    let mut scratch = vec![Complex::default(); conv_size].into_boxed_slice();
+                    $apply_stages(&mut kernel, &mut scratch, &stages, true);
+                    kernel
+                };
+                let forward_kernel = build_kernel(&forward_chirp);
+                let reverse_kernel = build_kernel(&reverse_chirp);
+
+                Some(Self {
+                    size,
+                    conv_size,
+                    forward_chirp,
+                    reverse_chirp,
+                    forward_kernel,
+                    reverse_kernel,
+                    stages,
+                    work: Cell::new(vec![Complex::default(); conv_size].into_boxed_slice()),
+                    buffer: Cell::new(vec![Complex::default(); conv_size].into_boxed_slice()),
+                })
+            }
+        }
+
+        impl Fft for $name {
+            type Real = $type;
+
+            fn size(&self) -> usize {
+                self.size
+            }
+
+            fn transform_in_place(&self, input: &mut [Complex<$type>], forward: bool) {
+                let (chirp, kernel) = if forward {
+                    (&self.forward_chirp, &self.forward_kernel)
+                } else {
+                    (&self.reverse_chirp, &self.reverse_kernel)
+                };
+
+                let mut buffer = self.buffer.take();
+                for x in buffer.iter_mut() {
+                    *x = Complex::default();
+                }
+                for (n, x) in input.iter().enumerate().take(self.size) {
+                    buffer[n] = x * chirp[n];
+                }
+
+                let mut work = self.work.take();
+                $apply_stages(&mut buffer, &mut work, &self.stages, true);
+                for (b, k) in buffer.iter_mut().zip(kernel.iter()) {
+                    *b *= k;
+                }
+                $apply_stages(&mut buffer, &mut work, &self.stages, false);
+                self.work.set(work);
+
+                if forward {
+                    for (n, x) in input.iter_mut().enumerate().take(self.size) {
+                        *x = buffer[n] * chirp[n];
+                    }
+                } else {
+                    // `apply_stages(.., false)` only normalizes by `1 / conv_size`, which the
+                    // cyclic convolution needs regardless of direction. The mixed-radix engine's
+                    // inverse additionally scales by `1 / size`, so match that here.
+                    let scale = cast::<$type>(1.0) / cast::<$type>(self.size as f64);
+                    for (n, x) in input.iter_mut().enumerate().take(self.size) {
+                        *x = buffer[n] * chirp[n] * scale;
+                    }
+                }
+                self.buffer.set(buffer);
+            }
+        }
+    };
+}
+
+make_bluestein! { f32, Bluestein32, apply_stages_f32 }
+make_bluestein! { f64, Bluestein64, apply_stages_f64 }
@@ -0,0 +1,102 @@
+use crate::float::FftFloat;
+use num_complex::Complex;
+use num_traits::{FloatConst, NumCast};
+
+fn cast<T: FftFloat>(x: f64) -> T {
+    NumCast::from(x).unwrap()
+}
+
+/// The arithmetic surface a butterfly needs: real add/sub, scaling by a real constant, and
+/// rotation by `±i` (used to turn a real sum-of-sines term into the imaginary part of the
+/// result). Implemented for scalar `Complex<T>` (the narrow path) and by each platform's `Vector`
+/// type (the wide, SIMD path), so `butterfly5`/`butterfly7` compile to both without duplication.
+pub(crate) trait Butterfly<T>: Copy {
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    fn scale(self, factor: T) -> Self;
+    fn rotate(self, sign: T) -> Self;
+}
+
+impl<T: FftFloat> Butterfly<T> for Complex<T> {
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    #[inline]
+    fn scale(self, factor: T) -> Self {
+        self * factor
+    }
+
+    #[inline]
+    fn rotate(self, sign: T) -> Self {
+        Complex::new(-self.im * sign, self.re * sign)
+    }
+}
+
+/// Radix-5 butterfly, in the same split sum/difference form used by the narrow/wide radix-2/3/4
+/// kernels: the four nontrivial twiddle weights collapse to two cosine and two sine constants.
+pub(crate) fn butterfly5<T: FftFloat, V: Butterfly<T>>(x: [V; 5], forward: bool) -> [V; 5] {
+    let c1 = cast::<T>((T::PI().to_f64().unwrap() * 2.0 / 5.0).cos());
+    let c2 = cast::<T>((T::PI().to_f64().unwrap() * 4.0 / 5.0).cos());
+    let s1 = cast::<T>((T::PI().to_f64().unwrap() * 2.0 / 5.0).sin());
+    let s2 = cast::<T>((T::PI().to_f64().unwrap() * 4.0 / 5.0).sin());
+    let sign = if forward { -T::one() } else { T::one() };
+
+    let t0 = x[1].add(x[4]);
+    let t1 = x[1].sub(x[4]);
+    let t2 = x[2].add(x[3]);
+    let t3 = x[2].sub(x[3]);
+
+    let m1 = x[0].add(t0.scale(c1)).add(t2.scale(c2));
+    let m2 = x[0].add(t0.scale(c2)).add(t2.scale(c1));
+
+    let n1 = t1.scale(s1).add(t3.scale(s2)).rotate(sign);
+    let n2 = t1.scale(s2).sub(t3.scale(s1)).rotate(sign);
+
+    [x[0].add(t0).add(t2), m1.add(n1), m2.add(n2), m2.sub(n2), m1.sub(n1)]
+}
+
+/// Radix-7 butterfly. Like `butterfly5`, the six nontrivial twiddle weights are folded into
+/// three sum/difference pairs scaled by the real cosine and sine constants of the seventh roots
+/// of unity, avoiding a full 7x7 multiplication.
+pub(crate) fn butterfly7<T: FftFloat, V: Butterfly<T>>(x: [V; 7], forward: bool) -> [V; 7] {
+    let pi = T::PI().to_f64().unwrap();
+    let c1 = cast::<T>((pi * 2.0 / 7.0).cos());
+    let c2 = cast::<T>((pi * 4.0 / 7.0).cos());
+    let c3 = cast::<T>((pi * 6.0 / 7.0).cos());
+    let s1 = cast::<T>((pi * 2.0 / 7.0).sin());
+    let s2 = cast::<T>((pi * 4.0 / 7.0).sin());
+    let s3 = cast::<T>((pi * 6.0 / 7.0).sin());
+    let sign = if forward { -T::one() } else { T::one() };
+
+    let t1 = x[1].add(x[6]);
+    let d1 = x[1].sub(x[6]);
+    let t2 = x[2].add(x[5]);
+    let d2 = x[2].sub(x[5]);
+    let t3 = x[3].add(x[4]);
+    let d3 = x[3].sub(x[4]);
+
+    let m1 = x[0].add(t1.scale(c1)).add(t2.scale(c2)).add(t3.scale(c3));
+    let m2 = x[0].add(t1.scale(c2)).add(t2.scale(c3)).add(t3.scale(c1));
+    let m3 = x[0].add(t1.scale(c3)).add(t2.scale(c1)).add(t3.scale(c2));
+
+    let n1 = d1.scale(s1).add(d2.scale(s2)).add(d3.scale(s3)).rotate(sign);
+    let n2 = d1.scale(s2).sub(d2.scale(s3)).sub(d3.scale(s1)).rotate(sign);
+    let n3 = d1.scale(s3).sub(d2.scale(s1)).add(d3.scale(s2)).rotate(sign);
+
+    [
+        x[0].add(t1).add(t2).add(t3),
+        m1.add(n1),
+        m2.add(n2),
+        m3.add(n3),
+        m3.sub(n3),
+        m2.sub(n2),
+        m1.sub(n1),
+    ]
+}
@@ -0,0 +1,103 @@
+//! Convolution and polynomial multiplication built on top of the FFT plans: `a * b` in the time
+//! domain is a pointwise product in the frequency domain once both operands are zero-padded past
+//! their linear (non-circular) result length.
+use crate::autosort::prime_factor::{create_f32, create_f64, smallest_supported_size};
+use crate::fft::Fft;
+use num_complex::Complex;
+use std::cell::Cell;
+
+macro_rules! make_convolve {
+    { $type:ty, $convolve:ident, $convolve_real:ident, $create:ident } => {
+        /// Convolves two complex signals, returning a vector of length `a.len() + b.len() - 1`.
+        pub fn $convolve(a: &[Complex<$type>], b: &[Complex<$type>]) -> Vec<Complex<$type>> {
+            let result_len = a.len() + b.len() - 1;
+            let size = smallest_supported_size::<$type>(result_len);
+            let fft = $create(size);
+
+            let mut fa = vec![Complex::default(); size];
+            fa[..a.len()].copy_from_slice(a);
+            let mut fb = vec![Complex::default(); size];
+            fb[..b.len()].copy_from_slice(b);
+
+            fft.transform_in_place(&mut fa, true);
+            fft.transform_in_place(&mut fb, true);
+            for (x, y) in fa.iter_mut().zip(fb.iter()) {
+                *x *= y;
+            }
+            fft.transform_in_place(&mut fa, false);
+            fa.truncate(result_len);
+            fa
+        }
+
+        /// Convolves two real signals, returning a vector of length `a.len() + b.len() - 1`.
+        pub fn $convolve_real(a: &[$type], b: &[$type]) -> Vec<$type> {
+            let ca: Vec<_> = a.iter().map(|&x| Complex::new(x, 0.0)).collect();
+            let cb: Vec<_> = b.iter().map(|&x| Complex::new(x, 0.0)).collect();
+            $convolve(&ca, &cb).iter().map(|c| c.re).collect()
+        }
+    };
+}
+
+make_convolve! { f32, convolve_f32, convolve_real_f32, create_f32 }
+make_convolve! { f64, convolve_f64, convolve_real_f64, create_f64 }
+
+macro_rules! make_convolver {
+    { $type:ty, $name:ident, $create:ident } => {
+        /// Caches an FFT plan and the forward transform of a fixed kernel, so repeated FIR-style
+        /// filtering of different signals against the same kernel doesn't re-transform it.
+        pub struct $name {
+            fft: Box<dyn Fft<Real = $type> + Send>,
+            size: usize,
+            kernel_len: usize,
+            kernel_fft: Vec<Complex<$type>>,
+            buffer: Cell<Box<[Complex<$type>]>>,
+        }
+
+        impl $name {
+            /// Creates a convolver for `kernel`, ready to filter signals of up to `max_signal_len`.
+            pub fn new(kernel: &[Complex<$type>], max_signal_len: usize) -> Self {
+                let result_len = kernel.len() + max_signal_len - 1;
+                let size = smallest_supported_size::<$type>(result_len);
+                let fft = $create(size);
+
+                let mut kernel_fft = vec![Complex::default(); size];
+                kernel_fft[..kernel.len()].copy_from_slice(kernel);
+                fft.transform_in_place(&mut kernel_fft, true);
+
+                Self {
+                    fft,
+                    size,
+                    kernel_len: kernel.len(),
+                    kernel_fft,
+                    buffer: Cell::new(vec![Complex::default(); size].into_boxed_slice()),
+                }
+            }
+
+            /// Convolves `signal` against the cached kernel, returning a vector of length
+            /// `signal.len() + kernel_len - 1`.
+            pub fn convolve(&self, signal: &[Complex<$type>]) -> Vec<Complex<$type>> {
+                let result_len = self.kernel_len + signal.len() - 1;
+                assert!(result_len <= self.size, "signal too long for this convolver's plan");
+
+                let mut buffer = self.buffer.take();
+                for x in buffer.iter_mut() {
+                    *x = Complex::default();
+                }
+                buffer[..signal.len()].copy_from_slice(signal);
+
+                self.fft.transform_in_place(&mut buffer, true);
+                for (x, k) in buffer.iter_mut().zip(self.kernel_fft.iter()) {
+                    *x *= k;
+                }
+                self.fft.transform_in_place(&mut buffer, false);
+
+                let result = buffer[..result_len].to_vec();
+                self.buffer.set(buffer);
+                result
+            }
+        }
+    };
+}
+
+make_convolver! { f32, Convolver32, create_f32 }
+make_convolver! { f64, Convolver64, create_f64 }
@@ -0,0 +1,47 @@
+//! Small modular-arithmetic helpers shared by the algorithms that need a primitive root of a
+//! prime modulus: Rader's algorithm (root of the transform size `p`) and the NTT (root of the
+//! NTT-friendly prime field).
+pub(crate) fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        exp >>= 1;
+        base = base * base % modulus;
+    }
+    result
+}
+
+pub(crate) fn mod_inverse(a: u64, modulus: u64) -> u64 {
+    mod_pow(a, modulus - 2, modulus)
+}
+
+fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            factors.push(d);
+            while n % d == 0 {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// The smallest primitive root of the prime `p`, found by checking candidates against every
+/// distinct prime factor of `p - 1`.
+pub(crate) fn primitive_root(p: u64) -> u64 {
+    let phi = p - 1;
+    let factors = distinct_prime_factors(phi);
+    (2..p)
+        .find(|&g| factors.iter().all(|&q| mod_pow(g, phi / q, p) != 1))
+        .expect("a primitive root always exists for a prime modulus")
+}
@@ -0,0 +1,208 @@
+//! AArch64 NEON vector support, mirroring the `avx` module's shape: a `neon_vector!` macro that
+//! brings a `Vector` type and `width!` into scope for the wide radix/stage functions. 128-bit NEON
+//! registers hold 2 packed `f32` complex numbers or 1 `f64` complex number, half AVX's width, so
+//! the partial-load ("narrow") vs full-load ("wide") split in `make_stage_fns!` carries over
+//! unchanged.
+#![cfg(target_arch = "aarch64")]
+
+use crate::butterfly::Butterfly;
+use core::arch::aarch64::*;
+use num_complex::Complex;
+use std::ops::{Add, Mul, Sub};
+
+/// 2 packed `Complex<f32>` lanes (`[re0, im0, re1, im1]`) in one 128-bit NEON register.
+#[derive(Clone, Copy)]
+pub(crate) struct NeonF32(float32x4_t);
+
+impl NeonF32 {
+    pub(crate) const LANES: usize = 2;
+
+    #[inline]
+    pub(crate) unsafe fn load(ptr: *const Complex<f32>) -> Self {
+        Self(vld1q_f32(ptr as *const f32))
+    }
+
+    #[inline]
+    pub(crate) unsafe fn store(self, ptr: *mut Complex<f32>) {
+        vst1q_f32(ptr as *mut f32, self.0)
+    }
+}
+
+impl Add for NeonF32 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(vaddq_f32(self.0, other.0)) }
+    }
+}
+
+impl Sub for NeonF32 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        unsafe { Self(vsubq_f32(self.0, other.0)) }
+    }
+}
+
+/// Scalar broadcast multiply: scales both lanes' real and imaginary parts by the same real
+/// constant, used for the radix-5/7 cosine/sine weights.
+impl Mul<f32> for NeonF32 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, factor: f32) -> Self {
+        unsafe { Self(vmulq_n_f32(self.0, factor)) }
+    }
+}
+
+/// Lane-wise complex multiply: `self[i] * other[i]` for each packed complex number.
+impl Mul for NeonF32 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        unsafe {
+            let other_re = vtrn1q_f32(other.0, other.0);
+            let other_im = vtrn2q_f32(other.0, other.0);
+            let self_swapped = vrev64q_f32(self.0);
+            let ac_bd = vmulq_f32(self.0, other_re);
+            let ad_bc = vmulq_f32(self_swapped, other_im);
+            let sign = vld1q_f32([-1.0f32, 1.0, -1.0, 1.0].as_ptr());
+            Self(vmlaq_f32(ac_bd, ad_bc, sign))
+        }
+    }
+}
+
+impl Butterfly<f32> for NeonF32 {
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Add::add(self, other)
+    }
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Sub::sub(self, other)
+    }
+
+    #[inline]
+    fn scale(self, factor: f32) -> Self {
+        self * factor
+    }
+
+    #[inline]
+    fn rotate(self, sign: f32) -> Self {
+        unsafe {
+            let swapped = vrev64q_f32(self.0);
+            let sign_pattern = vld1q_f32([-sign, sign, -sign, sign].as_ptr());
+            Self(vmulq_f32(swapped, sign_pattern))
+        }
+    }
+}
+
+/// 1 `Complex<f64>` (`[re, im]`) in one 128-bit NEON register.
+#[derive(Clone, Copy)]
+pub(crate) struct NeonF64(float64x2_t);
+
+impl NeonF64 {
+    pub(crate) const LANES: usize = 1;
+
+    #[inline]
+    pub(crate) unsafe fn load(ptr: *const Complex<f64>) -> Self {
+        Self(vld1q_f64(ptr as *const f64))
+    }
+
+    #[inline]
+    pub(crate) unsafe fn store(self, ptr: *mut Complex<f64>) {
+        vst1q_f64(ptr as *mut f64, self.0)
+    }
+}
+
+impl Add for NeonF64 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        unsafe { Self(vaddq_f64(self.0, other.0)) }
+    }
+}
+
+impl Sub for NeonF64 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        unsafe { Self(vsubq_f64(self.0, other.0)) }
+    }
+}
+
+impl Mul<f64> for NeonF64 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, factor: f64) -> Self {
+        unsafe { Self(vmulq_n_f64(self.0, factor)) }
+    }
+}
+
+impl Mul for NeonF64 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        unsafe {
+            let other_re = vdupq_laneq_f64(other.0, 0);
+            let other_im = vdupq_laneq_f64(other.0, 1);
+            let self_swapped = vextq_f64(self.0, self.0, 1);
+            let ac_bd = vmulq_f64(self.0, other_re);
+            let ad_bc = vmulq_f64(self_swapped, other_im);
+            let sign = vld1q_f64([-1.0f64, 1.0].as_ptr());
+            Self(vaddq_f64(ac_bd, vmulq_f64(ad_bc, sign)))
+        }
+    }
+}
+
+impl Butterfly<f64> for NeonF64 {
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Add::add(self, other)
+    }
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Sub::sub(self, other)
+    }
+
+    #[inline]
+    fn scale(self, factor: f64) -> Self {
+        self * factor
+    }
+
+    #[inline]
+    fn rotate(self, sign: f64) -> Self {
+        unsafe {
+            let swapped = vextq_f64(self.0, self.0, 1);
+            let sign_pattern = vld1q_f64([-sign, sign].as_ptr());
+            Self(vmulq_f64(swapped, sign_pattern))
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! neon_vector {
+    { f32 } => {
+        #[allow(unused_imports)]
+        use $crate::neon::NeonF32 as Vector;
+        macro_rules! width {
+            {} => { $crate::neon::NeonF32::LANES };
+        }
+    };
+    { f64 } => {
+        #[allow(unused_imports)]
+        use $crate::neon::NeonF64 as Vector;
+        macro_rules! width {
+            {} => { $crate::neon::NeonF64::LANES };
+        }
+    };
+}
+
+/// No size-specific fast paths yet; the generic NEON butterfly loop handles every radix.
+#[macro_export]
+macro_rules! neon_optimization {
+    ($type:ty, $width:ident, $radix:literal, $input:ident, $output:ident, $forward:ident, $size:ident, $stride:ident, $twiddles:ident) => {
+        false
+    };
+}
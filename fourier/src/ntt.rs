@@ -0,0 +1,177 @@
+//! Number-theoretic transform: an integer analogue of the FFT over a prime field `Z/p` with
+//! `p = c * 2^k + 1`, giving exact (rounding-free) convolution of integers or polynomials.
+use crate::modular::{mod_inverse, mod_pow, primitive_root};
+
+/// Primes of the form `c * 2^k + 1` commonly used for NTTs; `convolve_crt` combines results
+/// under all three.
+const NTT_PRIMES: [u32; 3] = [998_244_353, 167_772_161, 469_762_049];
+
+/// The known primitive root of each of `NTT_PRIMES`, used as a fast path instead of factoring
+/// `p - 1`.
+fn known_primitive_root(modulus: u32) -> Option<u32> {
+    match modulus {
+        998_244_353 | 167_772_161 | 469_762_049 => Some(3),
+        _ => None,
+    }
+}
+
+/// Precomputed per-stage root tables for an iterative radix-2 NTT of a fixed size and modulus,
+/// analogous to `Stages`'s `forward_twiddles`/`reverse_twiddles`.
+pub(crate) struct NttStages {
+    size: usize,
+    modulus: u32,
+    forward_roots: Vec<u32>,
+    reverse_roots: Vec<u32>,
+    inv_size: u32,
+}
+
+impl NttStages {
+    pub(crate) fn new(size: usize, modulus: u32) -> Option<Self> {
+        if size == 0 || !size.is_power_of_two() {
+            return None;
+        }
+        let phi = (modulus - 1) as u64;
+        if phi % size as u64 != 0 {
+            return None;
+        }
+        let g = known_primitive_root(modulus)
+            .unwrap_or_else(|| primitive_root(modulus as u64) as u32);
+        let omega = mod_pow(g as u64, phi / size as u64, modulus as u64) as u32;
+        let omega_inv = mod_inverse(omega as u64, modulus as u64) as u32;
+
+        let build_roots = |root: u32| {
+            let mut roots = Vec::with_capacity(size - 1);
+            let mut len = 2;
+            while len <= size {
+                let half = len / 2;
+                let stage_root = mod_pow(root as u64, (size / len) as u64, modulus as u64) as u32;
+                let mut w = 1u64;
+                for _ in 0..half {
+                    roots.push(w as u32);
+                    w = w * stage_root as u64 % modulus as u64;
+                }
+                len *= 2;
+            }
+            roots
+        };
+        let forward_roots = build_roots(omega);
+        let reverse_roots = build_roots(omega_inv);
+        let inv_size = mod_pow(size as u64, modulus as u64 - 2, modulus as u64) as u32;
+
+        Some(Self {
+            size,
+            modulus,
+            forward_roots,
+            reverse_roots,
+            inv_size,
+        })
+    }
+}
+
+fn bit_reverse_permute(a: &mut [u32]) {
+    let n = a.len();
+    if n < 2 {
+        return;
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = (i as u32).reverse_bits() >> (32 - bits);
+        if i < j as usize {
+            a.swap(i, j as usize);
+        }
+    }
+}
+
+fn apply_ntt(a: &mut [u32], stages: &NttStages, forward: bool) {
+    assert_eq!(a.len(), stages.size);
+    bit_reverse_permute(a);
+
+    let roots = if forward {
+        &stages.forward_roots
+    } else {
+        &stages.reverse_roots
+    };
+    let modulus = stages.modulus as u64;
+    let mut len = 2;
+    let mut root_offset = 0;
+    while len <= a.len() {
+        let half = len / 2;
+        for block in (0..a.len()).step_by(len) {
+            for k in 0..half {
+                let w = roots[root_offset + k] as u64;
+                let u = a[block + k] as u64;
+                let v = a[block + k + half] as u64 * w % modulus;
+                a[block + k] = ((u + v) % modulus) as u32;
+                a[block + k + half] = ((u + modulus - v) % modulus) as u32;
+            }
+        }
+        root_offset += half;
+        len *= 2;
+    }
+
+    if !forward {
+        for x in a.iter_mut() {
+            *x = (*x as u64 * stages.inv_size as u64 % modulus) as u32;
+        }
+    }
+}
+
+/// Runs an in-place NTT of `a` (whose length must be a power of two dividing `modulus - 1`) over
+/// `Z/modulus`, forward or inverse.
+pub fn ntt_transform(a: &mut [u32], modulus: u32, forward: bool) {
+    let stages =
+        NttStages::new(a.len(), modulus).expect("size must be a power of two dividing modulus - 1");
+    apply_ntt(a, &stages, forward);
+}
+
+/// Exact modular convolution of `a` and `b` over `Z/modulus`, with no rounding error.
+pub fn convolve(a: &[u32], b: &[u32], modulus: u32) -> Vec<u32> {
+    let result_len = a.len() + b.len() - 1;
+    let size = result_len.next_power_of_two();
+    let stages = NttStages::new(size, modulus).expect("modulus does not support this size");
+
+    let mut fa = vec![0u32; size];
+    fa[..a.len()].copy_from_slice(a);
+    let mut fb = vec![0u32; size];
+    fb[..b.len()].copy_from_slice(b);
+
+    apply_ntt(&mut fa, &stages, true);
+    apply_ntt(&mut fb, &stages, true);
+    for (x, y) in fa.iter_mut().zip(fb.iter()) {
+        *x = (*x as u64 * *y as u64 % modulus as u64) as u32;
+    }
+    apply_ntt(&mut fa, &stages, false);
+
+    fa.truncate(result_len);
+    fa
+}
+
+/// Combines the result of convolving `a` and `b` under each of the three `NTT_PRIMES` via the
+/// Chinese Remainder Theorem, giving products too large to fit under a single NTT-friendly prime.
+pub fn convolve_crt(a: &[u32], b: &[u32]) -> Vec<u128> {
+    let per_prime: Vec<Vec<u32>> = NTT_PRIMES
+        .iter()
+        .map(|&modulus| convolve(a, b, modulus))
+        .collect();
+
+    let p0 = NTT_PRIMES[0] as u128;
+    let p1 = NTT_PRIMES[1] as u128;
+    let p2 = NTT_PRIMES[2] as u128;
+    let inv_p0_mod_p1 = mod_inverse(p0 as u64 % p1 as u64, p1 as u64) as u128;
+    let p0p1 = p0 * p1;
+    let inv_p0p1_mod_p2 = mod_inverse((p0p1 % p2) as u64, p2 as u64) as u128;
+
+    (0..per_prime[0].len())
+        .map(|i| {
+            let (r0, r1, r2) = (
+                per_prime[0][i] as u128,
+                per_prime[1][i] as u128,
+                per_prime[2][i] as u128,
+            );
+            let t1 = ((r1 + p1 - r0 % p1) % p1) * inv_p0_mod_p1 % p1;
+            let x1 = r0 + p0 * t1;
+            let t2 = ((r2 + p2 - x1 % p2) % p2) * inv_p0p1_mod_p2 % p2;
+            x1 + p0p1 * t2
+        })
+        .collect()
+}
@@ -0,0 +1,146 @@
+//! Rader's algorithm, used for prime sizes whose predecessor factors cleanly into the supported
+//! radices. This avoids the ~2x zero-padding that Bluestein's algorithm needs, since a prime-length
+//! DFT turns into a cyclic convolution of length `p - 1` instead of a linear one of length `2p - 1`.
+use crate::autosort::prime_factor::{apply_stages_f32, apply_stages_f64, Stages};
+use crate::fft::Fft;
+use crate::float::FftFloat;
+use crate::modular::{mod_pow, primitive_root};
+use num_complex::Complex;
+use num_traits::NumCast;
+use std::cell::Cell;
+
+fn cast<T: FftFloat>(x: f64) -> T {
+    NumCast::from(x).unwrap()
+}
+
+pub(crate) fn is_prime(n: usize) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+macro_rules! make_rader {
+    { $type:ty, $name:ident, $apply_stages:ident } => {
+        pub(crate) struct $name {
+            size: usize,
+            permutation: Vec<usize>,
+            inverse_permutation: Vec<usize>,
+            conv_size: usize,
+            forward_kernel: Vec<Complex<$type>>,
+            reverse_kernel: Vec<Complex<$type>>,
+            stages: Stages<$type>,
+            work: Cell<Box<[Complex<$type>]>>,
+            buffer: Cell<Box<[Complex<$type>]>>,
+        }
+
+        impl $name {
+            pub(crate) fn new(size: usize) -> Option<Self> {
+                if !is_prime(size) {
+                    return None;
+                }
+                let l = size - 1;
+                let stages = Stages::new(l)?;
+
+                let g = primitive_root(size as u64) as usize;
+                let permutation: Vec<usize> = (0..l)
+                    .scan(1usize, |state, _| {
+                        let current = *state;
+                        *state = *state * g % size;
+                        Some(current)
+                    })
+                    .collect();
+
+                let g_inv = mod_pow(g as u64, l as u64 - 1, size as u64) as usize;
+                let inverse_powers: Vec<usize> = (0..l)
+                    .scan(1usize, |state, _| {
+                        let current = *state;
+                        *state = *state * g_inv % size;
+                        Some(current)
+                    })
+                    .collect();
+
+                let conv_size = l;
+                let build_kernel = |sign: f64| {
+                    let mut kernel = vec![Complex::default(); conv_size];
+                    for (s, &power) in inverse_powers.iter().enumerate() {
+                        let angle = sign * 2.0 * std::f64::consts::PI * power as f64 / size as f64;
+                        kernel[s] = Complex::new(cast(angle.cos()), cast(angle.sin()));
+                    }
+                    let mut scratch = vec![Complex::default(); conv_size].into_boxed_slice();
+                    $apply_stages(&mut kernel, &mut scratch, &stages, true);
+                    kernel
+                };
+                let forward_kernel = build_kernel(-1.0);
+                let reverse_kernel = build_kernel(1.0);
+
+                Some(Self {
+                    size,
+                    permutation,
+                    inverse_permutation: inverse_powers,
+                    conv_size,
+                    forward_kernel,
+                    reverse_kernel,
+                    stages,
+                    work: Cell::new(vec![Complex::default(); conv_size].into_boxed_slice()),
+                    buffer: Cell::new(vec![Complex::default(); conv_size].into_boxed_slice()),
+                })
+            }
+        }
+
+        impl Fft for $name {
+            type Real = $type;
+
+            fn size(&self) -> usize {
+                self.size
+            }
+
+            fn transform_in_place(&self, input: &mut [Complex<$type>], forward: bool) {
+                let kernel = if forward {
+                    &self.forward_kernel
+                } else {
+                    &self.reverse_kernel
+                };
+                let x0 = input[0];
+                let sum: Complex<$type> = input.iter().copied().sum();
+
+                let mut buffer = self.buffer.take();
+                for (q, &index) in self.permutation.iter().enumerate() {
+                    buffer[q] = input[index];
+                }
+
+                let mut work = self.work.take();
+                $apply_stages(&mut buffer, &mut work, &self.stages, true);
+                for (b, k) in buffer.iter_mut().zip(kernel.iter()) {
+                    *b *= k;
+                }
+                $apply_stages(&mut buffer, &mut work, &self.stages, false);
+                self.work.set(work);
+
+                // `apply_stages(.., false)` only normalizes the length-`p - 1` convolution by
+                // `1 / (p - 1)`. The mixed-radix engine's inverse additionally scales the whole
+                // result by `1 / size`, so match that here.
+                let scale = if forward {
+                    cast::<$type>(1.0)
+                } else {
+                    cast::<$type>(1.0) / cast::<$type>(self.size as f64)
+                };
+                for (k, &index) in self.inverse_permutation.iter().enumerate() {
+                    input[index] = (x0 + buffer[k]) * scale;
+                }
+                input[0] = sum * scale;
+                self.buffer.set(buffer);
+            }
+        }
+    };
+}
+
+make_rader! { f32, Rader32, apply_stages_f32 }
+make_rader! { f64, Rader64, apply_stages_f64 }